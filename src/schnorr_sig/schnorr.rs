@@ -1,9 +1,10 @@
 use ark_bls12_381::{Fr as ScalarField, G1Affine, G1Projective};
-use ark_ec::{CurveGroup, Group};
-use ark_ff::{Field, Fp, MontBackend, MontConfig, UniformRand};
-use ark_serialize::CanonicalSerialize;
-use sha2::{Digest, Sha256};
+use ark_ec::{AffineRepr, CurveGroup, Group, VariableBaseMSM};
+use ark_ff::{Field, Fp, MontBackend, MontConfig, PrimeField, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use sha2::{Digest, Sha512};
 use std::ops::Mul;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 pub struct SchnorrSig {}
 
@@ -15,76 +16,845 @@ pub struct SchnorrSig {}
 pub struct FqConfig;
 pub type Fq = Fp<MontBackend<FqConfig, 6>, 6>;
 
+/// A schnorrkel-style transcript that binds a Schnorr challenge to an
+/// application-chosen domain-separation label and any number of labeled
+/// message chunks, before absorbing the signer's public key and nonce
+/// commitment and squeezing the final challenge scalar.
+///
+/// Without a bound label, a challenge is just `H(message, u_t)`, so a
+/// signature produced for one protocol can be replayed as valid in any other
+/// protocol that happens to hash the same bytes. Starting every transcript
+/// with a context label closes that off. The digest `D` stays generic (as in
+/// [`SchnorrSig::challenge`]) so callers can still pick SHA-256, SHA-512, or a
+/// domain-tagged hash; it defaults to SHA-512 to match the crate's default.
+pub struct Transcript<D: Digest = Sha512> {
+    hasher: D,
+}
+
+impl<D: Digest> Transcript<D> {
+    /// Starts a transcript bound to `ctx_label` (e.g. `b"my-app-v1"`).
+    pub fn new(ctx_label: &[u8]) -> Self {
+        let mut hasher = D::new();
+        hasher.update(b"schnorr-sig-transcript-v1");
+        hasher.update(ctx_label.len().to_le_bytes());
+        hasher.update(ctx_label);
+
+        Transcript { hasher }
+    }
+
+    /// Absorbs a labeled chunk of message data, so a multi-part message can be
+    /// fed in piece by piece without manual, ambiguity-prone concatenation.
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) -> &mut Self {
+        self.hasher.update(label.len().to_le_bytes());
+        self.hasher.update(label);
+        self.hasher.update(message.len().to_le_bytes());
+        self.hasher.update(message);
+
+        self
+    }
+
+    /// Absorbs the signer's public key and nonce commitment, then squeezes
+    /// the Fiat-Shamir challenge scalar out of the accumulated transcript.
+    fn challenge(mut self, public_key: &G1Affine, u_t: &G1Affine) -> ScalarField {
+        let mut point_bytes = Vec::new();
+        public_key
+            .serialize_compressed(&mut point_bytes)
+            .expect("Serialization failed");
+        u_t.serialize_compressed(&mut point_bytes)
+            .expect("Serialization failed");
+        self.hasher.update(&point_bytes);
+
+        ScalarField::from_le_bytes_mod_order(&self.hasher.finalize())
+    }
+}
+
 impl SchnorrSig {
-    fn generate_keypair() -> (ScalarField, G1Affine) {
-        // private key
-        let private_key: ScalarField = ScalarField::rand(&mut rand::thread_rng());
-        // public key
-        let public_key = G1Projective::generator() * private_key;
-        // public key in projective coordinates
-        let public_key_projective = G1Projective::new(
-            public_key.x.into(),
-            public_key.y.into(),
-            public_key.z.into(),
-        );
-        // convert to affine coordinates
-        let public_key_affine: G1Affine = public_key_projective.into_affine();
+    /// The context label used by [`SchnorrSig::sign`] and [`SchnorrSig::verify`]
+    /// when the caller doesn't need domain separation from other protocols.
+    pub const DEFAULT_CONTEXT: &'static [u8] = b"schnorr-sig-default-context";
+
+    fn generate_keypair() -> (SecretKey, PublicKey) {
+        let secret_key = SecretKey::generate();
+        let public_key = PublicKey::from_secret_key(&secret_key);
+
+        (secret_key, public_key)
+    }
 
-        (private_key, public_key_affine)
+    fn sign(secret_key: &SecretKey, message: &[u8]) -> Signature {
+        SchnorrSig::sign_with_context::<Sha512>(secret_key, SchnorrSig::DEFAULT_CONTEXT, message)
     }
 
-    fn sign(private_key: ScalarField, message: &[u8]) -> (G1Affine, ScalarField) {
-        // Generate a random nonce alpha_t from Zq
+    /// Signs `message` with a nonce derived deterministically from the private key,
+    /// the message and `aux_rand`, instead of drawing it from an RNG.
+    ///
+    /// Reusing a randomized nonce across two signatures leaks the private key
+    /// (`sk = (z1 - z2) / (c1 - c2)`), so this avoids depending on the quality of
+    /// the RNG at signing time. `aux_rand` may be empty for fully reproducible
+    /// signatures (useful in tests), or filled with fresh randomness to get a
+    /// "synthetic nonce" that also resists fault attacks on the hash.
+    pub fn sign_deterministic(secret_key: &SecretKey, message: &[u8], aux_rand: &[u8]) -> Signature {
+        let alpha_t = SchnorrSig::derive_nonce(secret_key, message, aux_rand);
+
+        SchnorrSig::finish_signing::<Sha512>(secret_key, alpha_t, SchnorrSig::DEFAULT_CONTEXT, message)
+    }
+
+    /// Signs `message` with a randomized nonce, binding the challenge to
+    /// `ctx_label` so the signature can't be replayed as valid in a different
+    /// protocol that hashes the same message bytes.
+    ///
+    /// The digest `D` is generic, as with [`SchnorrSig::challenge`], so callers
+    /// can choose SHA-256, SHA-512, or a domain-tagged hash. Applications that
+    /// talk to more than one Schnorr-based protocol should pick a unique label
+    /// (e.g. `b"my-app-v1"`) and use it consistently for both signing and
+    /// [`SchnorrSig::verify_with_context`].
+    pub fn sign_with_context<D: Digest>(secret_key: &SecretKey, ctx_label: &[u8], message: &[u8]) -> Signature {
         let alpha_t: ScalarField = ScalarField::rand(&mut rand::thread_rng());
 
-        // Compute u_t = g^alpha_t
-        let u_t = G1Projective::generator() * alpha_t;
-        let u_t_affine: G1Affine = u_t.into_affine();
+        SchnorrSig::finish_signing::<D>(secret_key, alpha_t, ctx_label, message)
+    }
+
+    /// Completes a signature given a nonce `alpha_t`, by committing `u_t =
+    /// g^alpha_t`, deriving the context-bound challenge, and solving for `alpha_z`.
+    fn finish_signing<D: Digest>(secret_key: &SecretKey, alpha_t: ScalarField, ctx_label: &[u8], message: &[u8]) -> Signature {
+        let u_t: G1Affine = (G1Projective::generator() * alpha_t).into_affine();
 
-        // Hash message and u_t to get c
-        let c = SchnorrSig::hash_message_and_ut(message, &u_t_affine);
+        let public_key = PublicKey::from_secret_key(secret_key);
+        let c = SchnorrSig::challenge::<D>(ctx_label, &public_key.0, message, &u_t);
 
         // Compute alpha_z = alpha_t + alpha_c
-        let alpha_z = alpha_t + (private_key * c);
+        let alpha_z = alpha_t + (*secret_key.expose_scalar() * c);
 
-        (u_t_affine, alpha_z)
+        Signature(u_t, alpha_z)
     }
 
-    fn hash_message_and_ut(message: &[u8], u_t: &G1Affine) -> ScalarField {
-        let mut u_t_serialized_bytes = Vec::new();
-
-        u_t.serialize_compressed(&mut u_t_serialized_bytes)
+    /// Derives `alpha_t = H_nonce(sk || message || aux_rand) mod q`.
+    ///
+    /// The SHA-512 digest is interpreted as a little-endian integer and reduced
+    /// modulo the scalar field order, so the derivation always succeeds and is
+    /// uniform over `ScalarField`, unlike a direct parse of a 32-byte digest.
+    fn derive_nonce(secret_key: &SecretKey, message: &[u8], aux_rand: &[u8]) -> ScalarField {
+        let mut sk_bytes = Vec::new();
+        secret_key
+            .expose_scalar()
+            .serialize_compressed(&mut sk_bytes)
             .expect("Serialization failed");
 
-        let mut hasher = Sha256::new();
+        let mut hasher = Sha512::new();
+        hasher.update(&sk_bytes);
         hasher.update(message);
-        hasher.update(&u_t_serialized_bytes);
+        hasher.update(aux_rand);
         let hash_result = hasher.finalize();
 
-        ScalarField::from_random_bytes(&hash_result)
-            .unwrap_or(ScalarField::rand(&mut rand::thread_rng()))
+        ScalarField::from_le_bytes_mod_order(&hash_result)
     }
 
-    pub fn verify(
-        public_key: G1Affine,
-        message: &[u8],
-        signature: (G1Affine, ScalarField),
-    ) -> bool {
-        let (u_t, alpha_z) = signature;
+    /// Computes the Fiat-Shamir challenge `c = H(ctx_label, pk, message, u_t)`
+    /// via [`Transcript`], binding the signature to `ctx_label` so it can't be
+    /// replayed as valid under a different protocol that hashes the same
+    /// message bytes.
+    ///
+    /// The digest `D` is generic so callers can pick SHA-256, SHA-512, or a
+    /// domain-tagged hash.
+    fn challenge<D: Digest>(ctx_label: &[u8], public_key: &G1Affine, message: &[u8], u_t: &G1Affine) -> ScalarField {
+        let mut transcript = Transcript::<D>::new(ctx_label);
+        transcript.append_message(b"message", message);
+        transcript.challenge(public_key, u_t)
+    }
+
+    pub fn verify(public_key: &PublicKey, message: &[u8], signature: &Signature) -> bool {
+        SchnorrSig::verify_with_context::<Sha512>(public_key, SchnorrSig::DEFAULT_CONTEXT, message, signature)
+    }
+
+    /// Verifies `signature` against the challenge bound to `ctx_label`, as
+    /// produced by [`SchnorrSig::sign_with_context`].
+    pub fn verify_with_context<D: Digest>(public_key: &PublicKey, ctx_label: &[u8], message: &[u8], signature: &Signature) -> bool {
+        let Signature(u_t, alpha_z) = *signature;
 
-        // compute c = H(m, u_t)
-        let c = SchnorrSig::hash_message_and_ut(message, &u_t);
+        // compute c = H(ctx_label, pk, m, u_t)
+        let c = SchnorrSig::challenge::<D>(ctx_label, &public_key.0, message, &u_t);
 
         // compute g = u_t * u^c
         let g = G1Projective::generator() * alpha_z;
 
-        let u_c = &public_key.mul(ScalarField::from(c));
+        let u_c = &public_key.0.mul(ScalarField::from(c));
 
         let g_prime = u_t + u_c;
 
         // check: g = u_t * u^c
         g == g_prime
     }
+
+    /// Verifies many `(public_key, message, signature)` tuples at once.
+    ///
+    /// For each tuple `i` this computes the default-context challenge
+    /// `c_i = H(DEFAULT_CONTEXT, pk_i, m_i, u_t_i)` and draws a random
+    /// non-zero weight `delta_i`, then checks the single aggregate equation
+    /// `g^(sum delta_i*z_i) == sum delta_i*(u_t_i + c_i*pk_i)` with one
+    /// multi-scalar multiplication instead of one scalar-mult per signature. The
+    /// random weights are essential: without them, two invalid signatures whose
+    /// errors are additive inverses of each other would cancel out and pass.
+    ///
+    /// Returns `true` only if the aggregate check holds. On `false`, callers can
+    /// fall back to [`SchnorrSig::verify_batch_find_failure`] to locate the
+    /// offending signature.
+    pub fn verify_batch(items: &[(PublicKey, &[u8], Signature)]) -> bool {
+        if items.is_empty() {
+            return true;
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut z_acc = ScalarField::from(0u64);
+        let mut rhs_bases = Vec::with_capacity(items.len() * 2);
+        let mut rhs_scalars = Vec::with_capacity(items.len() * 2);
+
+        for (public_key, message, Signature(u_t, alpha_z)) in items {
+            let mut delta = ScalarField::rand(&mut rng);
+            while delta.is_zero() {
+                delta = ScalarField::rand(&mut rng);
+            }
+
+            let c = SchnorrSig::challenge::<Sha512>(SchnorrSig::DEFAULT_CONTEXT, &public_key.0, message, u_t);
+
+            z_acc += delta * alpha_z;
+
+            rhs_bases.push(*u_t);
+            rhs_scalars.push(delta);
+
+            rhs_bases.push(public_key.0);
+            rhs_scalars.push(delta * c);
+        }
+
+        let lhs = G1Projective::generator() * z_acc;
+        let rhs = G1Projective::msm(&rhs_bases, &rhs_scalars).expect("bases and scalars match in length");
+
+        lhs == rhs
+    }
+
+    /// Single-verifies each tuple in order and returns the index of the first
+    /// one that fails, or `None` if every signature verifies. Intended as the
+    /// fallback path once [`SchnorrSig::verify_batch`] reports a failure.
+    pub fn verify_batch_find_failure(items: &[(PublicKey, &[u8], Signature)]) -> Option<usize> {
+        items
+            .iter()
+            .position(|(public_key, message, signature)| !SchnorrSig::verify(public_key, message, signature))
+    }
+}
+
+/// Errors returned when decoding keys or signatures from their canonical byte encoding.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The byte slice was not the expected fixed length for the type being decoded.
+    InvalidLength { expected: usize, actual: usize },
+    /// The bytes decoded but were not a canonical point/scalar (e.g. off-curve or unreduced).
+    InvalidEncoding,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidLength { expected, actual } => {
+                write!(f, "invalid length: expected {expected} bytes, got {actual}")
+            }
+            Error::InvalidEncoding => write!(f, "invalid canonical encoding"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A public key: a BLS12-381 G1 point, encoded as 48 compressed bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey(pub G1Affine);
+
+impl PublicKey {
+    pub const BYTE_LEN: usize = 48;
+
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_LEN] {
+        let mut bytes = [0u8; Self::BYTE_LEN];
+        self.0
+            .serialize_compressed(bytes.as_mut_slice())
+            .expect("Serialization failed");
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::BYTE_LEN {
+            return Err(Error::InvalidLength {
+                expected: Self::BYTE_LEN,
+                actual: bytes.len(),
+            });
+        }
+        G1Affine::deserialize_compressed(bytes)
+            .map(PublicKey)
+            .map_err(|_| Error::InvalidEncoding)
+    }
+
+    /// Derives the public key `g^sk` matching a [`SecretKey`].
+    pub fn from_secret_key(secret_key: &SecretKey) -> Self {
+        let point = G1Projective::generator() * *secret_key.expose_scalar();
+        PublicKey(point.into_affine())
+    }
+}
+
+/// A private key: a `ScalarField` element, encoded as 32 compressed bytes.
+///
+/// Zeroized on drop so key material doesn't linger in memory once the key goes
+/// out of scope. The inner scalar is only reachable through [`SecretKey::expose_scalar`]
+/// so call sites can't casually copy it out.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct SecretKey(ScalarField);
+
+impl SecretKey {
+    pub const BYTE_LEN: usize = 32;
+
+    pub fn generate() -> Self {
+        SecretKey(ScalarField::rand(&mut rand::thread_rng()))
+    }
+
+    pub(crate) fn expose_scalar(&self) -> &ScalarField {
+        &self.0
+    }
+
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_LEN] {
+        let mut bytes = [0u8; Self::BYTE_LEN];
+        self.0
+            .serialize_compressed(bytes.as_mut_slice())
+            .expect("Serialization failed");
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::BYTE_LEN {
+            return Err(Error::InvalidLength {
+                expected: Self::BYTE_LEN,
+                actual: bytes.len(),
+            });
+        }
+        ScalarField::deserialize_compressed(bytes)
+            .map(SecretKey)
+            .map_err(|_| Error::InvalidEncoding)
+    }
+}
+
+impl std::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretKey(..)")
+    }
+}
+
+/// A signature: `u_t` (48 compressed bytes) followed by `alpha_z` (32 compressed bytes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature(pub G1Affine, pub ScalarField);
+
+impl Signature {
+    pub const BYTE_LEN: usize = PublicKey::BYTE_LEN + SecretKey::BYTE_LEN;
+
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_LEN] {
+        let mut bytes = [0u8; Self::BYTE_LEN];
+        bytes[..PublicKey::BYTE_LEN].copy_from_slice(&PublicKey(self.0).to_bytes());
+        bytes[PublicKey::BYTE_LEN..].copy_from_slice(&SecretKey(self.1).to_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::BYTE_LEN {
+            return Err(Error::InvalidLength {
+                expected: Self::BYTE_LEN,
+                actual: bytes.len(),
+            });
+        }
+        let u_t = PublicKey::from_bytes(&bytes[..PublicKey::BYTE_LEN])?.0;
+        let alpha_z = SecretKey::from_bytes(&bytes[PublicKey::BYTE_LEN..])?.0;
+        Ok(Signature(u_t, alpha_z))
+    }
+}
+
+impl From<(G1Affine, ScalarField)> for Signature {
+    fn from(signature: (G1Affine, ScalarField)) -> Self {
+        Signature(signature.0, signature.1)
+    }
+}
+
+impl From<Signature> for (G1Affine, ScalarField) {
+    fn from(signature: Signature) -> Self {
+        (signature.0, signature.1)
+    }
+}
+
+/// `serde` support for keys and signatures, gated behind the `serde` feature.
+///
+/// Human-readable formats (e.g. JSON) encode as hex strings; binary formats use
+/// the raw canonical bytes directly, following the same convention as blsttc.
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{PublicKey, SecretKey, Signature};
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    macro_rules! impl_serde_for_byte_type {
+        ($ty:ty) => {
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    if serializer.is_human_readable() {
+                        serializer.serialize_str(&hex::encode(self.to_bytes()))
+                    } else {
+                        serializer.serialize_bytes(&self.to_bytes())
+                    }
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    if deserializer.is_human_readable() {
+                        let hex_str = String::deserialize(deserializer)?;
+                        let bytes = hex::decode(hex_str).map_err(D::Error::custom)?;
+                        <$ty>::from_bytes(&bytes).map_err(D::Error::custom)
+                    } else {
+                        let bytes = Vec::<u8>::deserialize(deserializer)?;
+                        <$ty>::from_bytes(&bytes).map_err(D::Error::custom)
+                    }
+                }
+            }
+        };
+    }
+
+    impl_serde_for_byte_type!(PublicKey);
+    impl_serde_for_byte_type!(SecretKey);
+    impl_serde_for_byte_type!(Signature);
+}
+
+/// MuSig-style key and signature aggregation, letting `n` signers jointly
+/// produce one [`Signature`] over a common message that verifies against one
+/// aggregate [`PublicKey`].
+pub struct MuSig {}
+
+/// The result of aggregating a set of public keys: the aggregate key `X = sum
+/// a_i*pk_i` together with each signer's coefficient `a_i = H(L || pk_i)`,
+/// where `L = H(pk_1 || ... || pk_n)`.
+///
+/// Hashing each signer's coefficient against `L` (which depends on every
+/// public key in the group) is what defeats the rogue-key attack: an attacker
+/// who doesn't know a cosigner's secret key can no longer choose their own
+/// public key to cancel the honest signers out of the aggregate, because
+/// their contribution is scaled by a coefficient they can't predict in
+/// advance.
+pub struct MuSigKeyAggregation {
+    pub aggregate_public_key: PublicKey,
+    coefficients: Vec<ScalarField>,
+}
+
+impl MuSigKeyAggregation {
+    pub fn new(public_keys: &[PublicKey]) -> Self {
+        let mut l_hasher = Sha512::new();
+        for public_key in public_keys {
+            let mut pk_bytes = Vec::new();
+            public_key
+                .0
+                .serialize_compressed(&mut pk_bytes)
+                .expect("Serialization failed");
+            l_hasher.update(&pk_bytes);
+        }
+        let l = l_hasher.finalize();
+
+        let mut coefficients = Vec::with_capacity(public_keys.len());
+        let mut aggregate: Option<G1Projective> = None;
+
+        for public_key in public_keys {
+            let mut pk_bytes = Vec::new();
+            public_key
+                .0
+                .serialize_compressed(&mut pk_bytes)
+                .expect("Serialization failed");
+
+            let mut hasher = Sha512::new();
+            hasher.update(l);
+            hasher.update(&pk_bytes);
+            let a_i = ScalarField::from_le_bytes_mod_order(&hasher.finalize());
+
+            coefficients.push(a_i);
+
+            let term = public_key.0.mul(a_i);
+            aggregate = Some(match aggregate {
+                Some(acc) => acc + term,
+                None => term,
+            });
+        }
+
+        let aggregate_public_key =
+            PublicKey(aggregate.expect("at least one public key").into_affine());
+
+        MuSigKeyAggregation {
+            aggregate_public_key,
+            coefficients,
+        }
+    }
+
+    /// Returns signer `index`'s coefficient `a_i`, needed to produce its partial signature.
+    pub fn coefficient(&self, index: usize) -> ScalarField {
+        self.coefficients[index]
+    }
+}
+
+/// A signer's nonce for one MuSig signing round: the secret `r_i` and its
+/// public commitment `u_i = g^r_i`, which gets published and summed into the
+/// aggregate nonce `R`.
+pub struct MuSigNonce {
+    secret: ScalarField,
+    pub commitment: G1Affine,
+}
+
+impl MuSigNonce {
+    pub fn generate() -> Self {
+        let secret = ScalarField::rand(&mut rand::thread_rng());
+        let commitment = (G1Projective::generator() * secret).into_affine();
+
+        MuSigNonce { secret, commitment }
+    }
+}
+
+/// A signer's partial signature `s_i = r_i + c*a_i*x_i`, combined with every
+/// other signer's via [`MuSig::aggregate_signature`] to produce the final signature.
+#[derive(Clone, Copy)]
+pub struct MuSigPartialSignature(pub ScalarField);
+
+impl MuSig {
+    /// Sums the per-signer nonce commitments into the aggregate nonce `R`.
+    pub fn aggregate_nonce_commitments(commitments: &[G1Affine]) -> G1Affine {
+        let mut commitments = commitments.iter();
+        let first = *commitments.next().expect("at least one nonce commitment");
+        let sum = commitments.fold(G1Projective::from(first), |acc, c| acc + c);
+
+        sum.into_affine()
+    }
+
+    /// Computes the MuSig challenge `c = H(X, R, m)`.
+    fn challenge(aggregate_public_key: &PublicKey, aggregate_nonce: &G1Affine, message: &[u8]) -> ScalarField {
+        let mut x_bytes = Vec::new();
+        aggregate_public_key
+            .0
+            .serialize_compressed(&mut x_bytes)
+            .expect("Serialization failed");
+
+        let mut r_bytes = Vec::new();
+        aggregate_nonce
+            .serialize_compressed(&mut r_bytes)
+            .expect("Serialization failed");
+
+        let mut hasher = Sha512::new();
+        hasher.update(&x_bytes);
+        hasher.update(&r_bytes);
+        hasher.update(message);
+
+        ScalarField::from_le_bytes_mod_order(&hasher.finalize())
+    }
+
+    /// Produces signer `index`'s partial signature for `message`.
+    ///
+    /// `aggregate_nonce` must be the sum of every signer's nonce commitment
+    /// (see [`MuSig::aggregate_nonce_commitments`]), and `coefficient` must be
+    /// that signer's `a_i` from the same [`MuSigKeyAggregation`] used to derive
+    /// `aggregate_public_key`.
+    pub fn partial_sign(
+        secret_key: &SecretKey,
+        nonce: &MuSigNonce,
+        coefficient: ScalarField,
+        aggregate_public_key: &PublicKey,
+        aggregate_nonce: &G1Affine,
+        message: &[u8],
+    ) -> MuSigPartialSignature {
+        let c = MuSig::challenge(aggregate_public_key, aggregate_nonce, message);
+        let s_i = nonce.secret + (c * coefficient * *secret_key.expose_scalar());
+
+        MuSigPartialSignature(s_i)
+    }
+
+    /// Combines every signer's partial signature into the final `(R, sum s_i)` signature.
+    pub fn aggregate_signature(aggregate_nonce: G1Affine, partial_signatures: &[MuSigPartialSignature]) -> Signature {
+        let s = partial_signatures
+            .iter()
+            .fold(ScalarField::from(0u64), |acc, partial| acc + partial.0);
+
+        Signature(aggregate_nonce, s)
+    }
+
+    /// Verifies a MuSig signature against the aggregate public key, re-deriving
+    /// the same challenge `c = H(X, R, m)` used during signing.
+    pub fn verify(aggregate_public_key: &PublicKey, message: &[u8], signature: &Signature) -> bool {
+        let Signature(r, s) = *signature;
+        let c = MuSig::challenge(aggregate_public_key, &r, message);
+
+        let g = G1Projective::generator() * s;
+        let rhs = r + aggregate_public_key.0.mul(c);
+
+        g == rhs
+    }
+}
+
+/// Threshold Schnorr signing: a secret key is split via Shamir secret sharing
+/// so that any `threshold` of `n` participants can jointly sign, but fewer
+/// cannot, without the secret key ever being reassembled in one place.
+pub struct ThresholdSchnorr {}
+
+/// A single Shamir share `(i, f(i))` of a split secret key.
+#[derive(Clone, Copy)]
+pub struct Share {
+    pub index: u64,
+    scalar: ScalarField,
+}
+
+/// The output of splitting a secret key: each participant's share, plus the
+/// Feldman VSS commitments `g^{coeff_j}` that let a share be checked against
+/// the split without revealing it (`commitments[0]` is the shared public key).
+pub struct ThresholdKeyShares {
+    pub shares: Vec<Share>,
+    pub commitments: Vec<G1Affine>,
+}
+
+/// A participant's partial response `z_i`, summed with every other
+/// participant's via [`ThresholdSchnorr::combine`] to produce the final signature.
+#[derive(Clone, Copy)]
+pub struct ThresholdPartialSignature(pub ScalarField);
+
+impl ThresholdSchnorr {
+    /// Splits `secret_key` into `n` shares of a degree-`(threshold - 1)`
+    /// polynomial `f` with `f(0) = secret_key`, such that any `threshold` of
+    /// them reconstruct it via Lagrange interpolation.
+    pub fn split_key(secret_key: &SecretKey, threshold: usize, n: usize) -> ThresholdKeyShares {
+        assert!(threshold >= 1 && threshold <= n, "threshold must be between 1 and n");
+
+        let mut rng = rand::thread_rng();
+        let mut coefficients = Vec::with_capacity(threshold);
+        coefficients.push(*secret_key.expose_scalar());
+        for _ in 1..threshold {
+            coefficients.push(ScalarField::rand(&mut rng));
+        }
+
+        let commitments = coefficients
+            .iter()
+            .map(|c| (G1Projective::generator() * *c).into_affine())
+            .collect();
+
+        let shares = (1..=n as u64)
+            .map(|i| {
+                let x = ScalarField::from(i);
+                let mut y = ScalarField::from(0u64);
+                let mut x_power = ScalarField::from(1u64);
+                for c in &coefficients {
+                    y += *c * x_power;
+                    x_power *= x;
+                }
+                Share { index: i, scalar: y }
+            })
+            .collect();
+
+        ThresholdKeyShares { shares, commitments }
+    }
+
+    /// Checks a share against the Feldman VSS commitments without learning the
+    /// secret: `g^{f(i)} == product_j commitments[j]^{i^j}`.
+    pub fn verify_share(share: &Share, commitments: &[G1Affine]) -> bool {
+        let x = ScalarField::from(share.index);
+        let mut x_power = ScalarField::from(1u64);
+
+        let mut commitments_iter = commitments.iter();
+        let first = *commitments_iter.next().expect("at least one commitment");
+        let mut rhs = G1Projective::from(first.mul(x_power));
+        x_power *= x;
+
+        for commitment in commitments_iter {
+            rhs += commitment.mul(x_power);
+            x_power *= x;
+        }
+
+        let lhs = G1Projective::generator() * share.scalar;
+        lhs == rhs
+    }
+
+    /// Computes the Lagrange coefficient `lambda_i = product_{j != i} j/(j - i)`
+    /// for participant `i` evaluated at `x = 0`, over the given set of
+    /// participating indices.
+    fn lagrange_coefficient(participant_indices: &[u64], i: u64) -> ScalarField {
+        let x_i = ScalarField::from(i);
+        let mut numerator = ScalarField::from(1u64);
+        let mut denominator = ScalarField::from(1u64);
+
+        for &j in participant_indices {
+            if j == i {
+                continue;
+            }
+            let x_j = ScalarField::from(j);
+            numerator *= x_j;
+            denominator *= x_j - x_i;
+        }
+
+        numerator * denominator.inverse().expect("participant indices must be distinct")
+    }
+
+    /// Combines the participating nonce commitments `(index, u_t_i)` into the
+    /// effective nonce `R = sum u_t_i`.
+    ///
+    /// This sum must stay unweighted: `partial_sign` folds each participant's
+    /// Lagrange coefficient into their *response* (`c*lambda_i*f(i)`), not
+    /// their nonce, so `R`'s discrete log is `sum r_i`, matching `sum z_i`.
+    pub fn combine_nonce_commitments(commitments: &[(u64, G1Affine)]) -> G1Affine {
+        let mut commitments_iter = commitments.iter();
+        let (_, first_commitment) = *commitments_iter.next().expect("at least one nonce commitment");
+        let mut r = G1Projective::from(first_commitment);
+
+        for &(_, commitment) in commitments_iter {
+            r += commitment;
+        }
+
+        r.into_affine()
+    }
+
+    /// Produces participant `share.index`'s partial response `z_i = r_i +
+    /// c*lambda_i*f(i)`, where `c` is the ordinary (default-context) Schnorr
+    /// challenge computed against the combined nonce `R` and the original
+    /// (unsplit) `public_key`, so the reconstructed signature verifies via
+    /// [`SchnorrSig::verify`].
+    pub fn partial_sign(
+        share: &Share,
+        nonce: &MuSigNonce,
+        participant_indices: &[u64],
+        public_key: &PublicKey,
+        combined_nonce: &G1Affine,
+        message: &[u8],
+    ) -> ThresholdPartialSignature {
+        let c = SchnorrSig::challenge::<Sha512>(SchnorrSig::DEFAULT_CONTEXT, &public_key.0, message, combined_nonce);
+        let lambda_i = ThresholdSchnorr::lagrange_coefficient(participant_indices, share.index);
+        let z_i = nonce.secret + (c * lambda_i * share.scalar);
+
+        ThresholdPartialSignature(z_i)
+    }
+
+    /// Sums every participant's partial response into the final `(R, z)`
+    /// signature, which verifies against the original (unsplit) public key via
+    /// [`SchnorrSig::verify`], the secret key never having been reassembled.
+    pub fn combine(combined_nonce: G1Affine, partial_signatures: &[ThresholdPartialSignature]) -> Signature {
+        let z = partial_signatures
+            .iter()
+            .fold(ScalarField::from(0u64), |acc, partial| acc + partial.0);
+
+        Signature(combined_nonce, z)
+    }
+}
+
+/// Hashes arbitrary bytes to a point on G1 via try-and-increment: hash the
+/// input with an incrementing counter until the candidate bytes decode as a
+/// point on the curve, then clear the cofactor to land in the prime-order
+/// subgroup.
+///
+/// Candidates are decoded with `deserialize_compressed_unchecked`, which only
+/// checks that the point is on the curve, not that it's in the correct
+/// subgroup — a uniformly random on-curve point only lands in the subgroup
+/// with probability `1/cofactor` (~2^-126 for BLS12-381 G1), so requiring
+/// subgroup membership up front would make this loop effectively infinite.
+fn hash_to_g1(input: &[u8]) -> G1Affine {
+    let mut counter: u64 = 0;
+    loop {
+        let mut hasher = Sha512::new();
+        hasher.update(b"hash-to-g1");
+        hasher.update(input);
+        hasher.update(counter.to_le_bytes());
+        let digest = hasher.finalize();
+
+        let mut candidate = [0u8; 48];
+        candidate.copy_from_slice(&digest[..48]);
+        // Clear the top bits ark-serialize reserves for the compression flags.
+        candidate[47] &= 0x3f;
+
+        if let Ok(point) = G1Affine::deserialize_compressed_unchecked(candidate.as_slice()) {
+            let point = point.clear_cofactor();
+            if !point.is_zero() {
+                return point;
+            }
+        }
+
+        counter += 1;
+    }
+}
+
+/// A VRF proof `(Gamma, c, s)` that `Gamma = sk*H` and `pk = g^sk` share the
+/// same discrete log, without revealing `sk`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VrfProof {
+    pub gamma: G1Affine,
+    pub c: ScalarField,
+    pub s: ScalarField,
+}
+
+/// The VRF's pseudorandom output, `H(Gamma)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VrfOutput(pub [u8; 64]);
+
+/// A Verifiable Random Function built on the crate's Schnorr proof machinery:
+/// a keyholder can produce a deterministic, unpredictable, publicly-verifiable
+/// pseudorandom output for an input, together with a proof anyone can check
+/// against the public key without learning the secret key.
+pub struct Vrf {}
+
+impl Vrf {
+    /// Computes `Gamma = sk*H` for `H = hash_to_g1(input)` and a Schnorr-style
+    /// proof that `Gamma` and `pk` share the same discrete log `sk`.
+    pub fn prove(secret_key: &SecretKey, input: &[u8]) -> (VrfOutput, VrfProof) {
+        let h = hash_to_g1(input);
+        let gamma = h.mul(*secret_key.expose_scalar()).into_affine();
+
+        let public_key = PublicKey::from_secret_key(secret_key);
+        let k = ScalarField::rand(&mut rand::thread_rng());
+        let g_k = (G1Projective::generator() * k).into_affine();
+        let h_k = h.mul(k).into_affine();
+
+        let c = Vrf::challenge(&public_key.0, &h, &gamma, &g_k, &h_k);
+        let s = k + c * *secret_key.expose_scalar();
+
+        (Vrf::output_from_gamma(&gamma), VrfProof { gamma, c, s })
+    }
+
+    /// Verifies that `output` and `proof` were produced by the holder of
+    /// `public_key` for `input`, by recomputing `g^s*pk^{-c}` and
+    /// `H^s*Gamma^{-c}` and checking they hash back to the proof's challenge `c`.
+    pub fn verify(public_key: &PublicKey, input: &[u8], output: &VrfOutput, proof: &VrfProof) -> bool {
+        let h = hash_to_g1(input);
+        let VrfProof { gamma, c, s } = *proof;
+
+        let g_k = (G1Projective::generator() * s + public_key.0.mul(-c)).into_affine();
+        let h_k = (h.mul(s) + gamma.mul(-c)).into_affine();
+
+        let expected_c = Vrf::challenge(&public_key.0, &h, &gamma, &g_k, &h_k);
+
+        expected_c == c && *output == Vrf::output_from_gamma(&gamma)
+    }
+
+    /// Computes the VRF challenge `c = H(g, H, pk, Gamma, g^k, H^k)`.
+    fn challenge(public_key: &G1Affine, h: &G1Affine, gamma: &G1Affine, g_k: &G1Affine, h_k: &G1Affine) -> ScalarField {
+        let mut bytes = Vec::new();
+        for point in [G1Affine::generator(), *h, *public_key, *gamma, *g_k, *h_k] {
+            point
+                .serialize_compressed(&mut bytes)
+                .expect("Serialization failed");
+        }
+
+        let mut hasher = Sha512::new();
+        hasher.update(&bytes);
+        ScalarField::from_le_bytes_mod_order(&hasher.finalize())
+    }
+
+    /// Derives the VRF output `H(Gamma)`.
+    fn output_from_gamma(gamma: &G1Affine) -> VrfOutput {
+        let mut gamma_bytes = Vec::new();
+        gamma
+            .serialize_compressed(&mut gamma_bytes)
+            .expect("Serialization failed");
+
+        let mut hasher = Sha512::new();
+        hasher.update(&gamma_bytes);
+
+        let mut output = [0u8; 64];
+        output.copy_from_slice(&hasher.finalize());
+        VrfOutput(output)
+    }
 }
 
 pub fn main() {
@@ -92,8 +862,8 @@ pub fn main() {
 
     let msg = b"Hello world!";
 
-    let sig = SchnorrSig::sign(sk, msg);
-    let verify = SchnorrSig::verify(pk, msg, sig);
+    let sig = SchnorrSig::sign(&sk, msg);
+    let verify = SchnorrSig::verify(&pk, msg, &sig);
     println!("Verify={}", verify);
 }
 
@@ -106,8 +876,8 @@ mod test {
         let (sk, pk) = SchnorrSig::generate_keypair();
         let msg = b"Hello world!";
 
-        let sig = SchnorrSig::sign(sk, msg);
-        let verify = SchnorrSig::verify(pk, msg, sig);
+        let sig = SchnorrSig::sign(&sk, msg);
+        let verify = SchnorrSig::verify(&pk, msg, &sig);
 
         assert_eq!(verify, true);
     }
@@ -119,8 +889,8 @@ mod test {
         let msg = b"Hello world!";
         let tampered_msg = b"Hello world!!";
 
-        let sig = SchnorrSig::sign(sk, msg);
-        let verify = SchnorrSig::verify(pk, tampered_msg, sig);
+        let sig = SchnorrSig::sign(&sk, msg);
+        let verify = SchnorrSig::verify(&pk, tampered_msg, &sig);
 
         assert_eq!(verify, false);
     }
@@ -131,12 +901,200 @@ mod test {
 
         let msg = b"Hello world!";
 
-        let (ut, _) = SchnorrSig::sign(sk, msg);
-        let verify = SchnorrSig::verify(pk, msg, (ut, ScalarField::rand(&mut rand::thread_rng())));
+        let Signature(ut, _) = SchnorrSig::sign(&sk, msg);
+        let tampered_sig = Signature(ut, ScalarField::rand(&mut rand::thread_rng()));
+        let verify = SchnorrSig::verify(&pk, msg, &tampered_sig);
 
         assert_eq!(verify, false);
     }
 
+    #[test]
+    fn test_sign_deterministic_verify() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+        let msg = b"Hello world!";
+
+        let sig = SchnorrSig::sign_deterministic(&sk, msg, &[]);
+        let verify = SchnorrSig::verify(&pk, msg, &sig);
+
+        assert_eq!(verify, true);
+    }
+
+    #[test]
+    fn test_sign_deterministic_is_reproducible() {
+        let (sk, _) = SchnorrSig::generate_keypair();
+        let msg = b"Hello world!";
+        let aux_rand = [7u8; 32];
+
+        let sig1 = SchnorrSig::sign_deterministic(&sk, msg, &aux_rand);
+        let sig2 = SchnorrSig::sign_deterministic(&sk, msg, &aux_rand);
+
+        assert_eq!(sig1, sig2);
+    }
+
+    #[test]
+    fn test_challenge_is_deterministic() {
+        let (_, pk) = SchnorrSig::generate_keypair();
+        let msg = b"Hello world!";
+
+        let c1 = SchnorrSig::challenge::<Sha512>(SchnorrSig::DEFAULT_CONTEXT, &pk.0, msg, &pk.0);
+        let c2 = SchnorrSig::challenge::<Sha512>(SchnorrSig::DEFAULT_CONTEXT, &pk.0, msg, &pk.0);
+
+        assert_eq!(c1, c2);
+    }
+
+    #[test]
+    fn test_challenge_differs_across_contexts() {
+        let (_, pk) = SchnorrSig::generate_keypair();
+        let msg = b"Hello world!";
+
+        let c1 = SchnorrSig::challenge::<Sha512>(b"protocol-a", &pk.0, msg, &pk.0);
+        let c2 = SchnorrSig::challenge::<Sha512>(b"protocol-b", &pk.0, msg, &pk.0);
+
+        assert_ne!(c1, c2);
+    }
+
+    #[test]
+    fn test_sign_verify_with_context() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+        let msg = b"Hello world!";
+
+        let sig = SchnorrSig::sign_with_context::<Sha512>(&sk, b"my-app-v1", msg);
+
+        assert_eq!(SchnorrSig::verify_with_context::<Sha512>(&pk, b"my-app-v1", msg, &sig), true);
+    }
+
+    #[test]
+    fn test_verify_with_context_rejects_wrong_context() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+        let msg = b"Hello world!";
+
+        let sig = SchnorrSig::sign_with_context::<Sha512>(&sk, b"my-app-v1", msg);
+
+        assert_eq!(SchnorrSig::verify_with_context::<Sha512>(&pk, b"other-app-v1", msg, &sig), false);
+    }
+
+    #[test]
+    fn test_verify_rejects_signature_from_different_context() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+        let msg = b"Hello world!";
+
+        let sig = SchnorrSig::sign_with_context::<Sha512>(&sk, b"my-app-v1", msg);
+
+        assert_eq!(SchnorrSig::verify(&pk, msg, &sig), false);
+    }
+
+    #[test]
+    fn test_sign_verify_with_context_pluggable_digest() {
+        use sha2::Sha256;
+
+        let (sk, pk) = SchnorrSig::generate_keypair();
+        let msg = b"Hello world!";
+
+        let sig = SchnorrSig::sign_with_context::<Sha256>(&sk, b"my-app-v1", msg);
+
+        assert_eq!(SchnorrSig::verify_with_context::<Sha256>(&pk, b"my-app-v1", msg, &sig), true);
+        // A signature made with one digest doesn't verify under a different one.
+        assert_eq!(SchnorrSig::verify_with_context::<Sha512>(&pk, b"my-app-v1", msg, &sig), false);
+    }
+
+    #[test]
+    fn test_verify_batch_all_valid() {
+        let (sk1, pk1) = SchnorrSig::generate_keypair();
+        let (sk2, pk2) = SchnorrSig::generate_keypair();
+        let (sk3, pk3) = SchnorrSig::generate_keypair();
+
+        let msg1 = b"Hello world!";
+        let msg2 = b"Another message";
+        let msg3 = b"Yet another message";
+
+        let sig1 = SchnorrSig::sign(&sk1, msg1);
+        let sig2 = SchnorrSig::sign(&sk2, msg2);
+        let sig3 = SchnorrSig::sign(&sk3, msg3);
+
+        let items = [
+            (pk1, msg1.as_slice(), sig1),
+            (pk2, msg2.as_slice(), sig2),
+            (pk3, msg3.as_slice(), sig3),
+        ];
+
+        assert_eq!(SchnorrSig::verify_batch(&items), true);
+    }
+
+    #[test]
+    fn test_verify_batch_rejects_tampered_signature() {
+        let (sk1, pk1) = SchnorrSig::generate_keypair();
+        let (sk2, pk2) = SchnorrSig::generate_keypair();
+
+        let msg1 = b"Hello world!";
+        let msg2 = b"Another message";
+
+        let sig1 = SchnorrSig::sign(&sk1, msg1);
+        let Signature(u_t2, _) = SchnorrSig::sign(&sk2, msg2);
+        let tampered_sig2 = Signature(u_t2, ScalarField::rand(&mut rand::thread_rng()));
+
+        let items = [
+            (pk1, msg1.as_slice(), sig1),
+            (pk2, msg2.as_slice(), tampered_sig2),
+        ];
+
+        assert_eq!(SchnorrSig::verify_batch(&items), false);
+        assert_eq!(SchnorrSig::verify_batch_find_failure(&items), Some(1));
+    }
+
+    #[test]
+    fn test_verify_batch_empty_is_valid() {
+        let items: [(PublicKey, &[u8], Signature); 0] = [];
+
+        assert_eq!(SchnorrSig::verify_batch(&items), true);
+    }
+
+    #[test]
+    fn test_public_key_roundtrip() {
+        let (_, pk) = SchnorrSig::generate_keypair();
+
+        let bytes = pk.to_bytes();
+        assert_eq!(bytes.len(), PublicKey::BYTE_LEN);
+
+        let decoded = PublicKey::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, pk);
+    }
+
+    #[test]
+    fn test_secret_key_roundtrip() {
+        let (sk, _) = SchnorrSig::generate_keypair();
+
+        let bytes = sk.to_bytes();
+        assert_eq!(bytes.len(), SecretKey::BYTE_LEN);
+
+        let decoded = SecretKey::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.expose_scalar(), sk.expose_scalar());
+    }
+
+    #[test]
+    fn test_signature_roundtrip() {
+        let (sk, _) = SchnorrSig::generate_keypair();
+        let msg = b"Hello world!";
+        let sig = SchnorrSig::sign(&sk, msg);
+
+        let bytes = sig.to_bytes();
+        assert_eq!(bytes.len(), Signature::BYTE_LEN);
+
+        let decoded = Signature::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, sig);
+    }
+
+    #[test]
+    fn test_public_key_from_bytes_rejects_wrong_length() {
+        let err = PublicKey::from_bytes(&[0u8; 10]).unwrap_err();
+        assert_eq!(
+            err,
+            Error::InvalidLength {
+                expected: PublicKey::BYTE_LEN,
+                actual: 10
+            }
+        );
+    }
+
     #[test]
     fn test_sign_verify_tampered_secret_key() {
         let (_, pk) = SchnorrSig::generate_keypair();
@@ -144,9 +1102,192 @@ mod test {
 
         let msg = b"Hello world!";
 
-        let tampered_sig = SchnorrSig::sign(tampered_sk, msg);
-        let tampered_verify = SchnorrSig::verify(pk, msg, tampered_sig);
+        let tampered_sig = SchnorrSig::sign(&tampered_sk, msg);
+        let tampered_verify = SchnorrSig::verify(&pk, msg, &tampered_sig);
 
         assert_eq!(tampered_verify, false);
     }
+
+    #[test]
+    fn test_public_key_from_secret_key_matches_generate_keypair() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+
+        assert_eq!(PublicKey::from_secret_key(&sk), pk);
+    }
+
+    #[test]
+    fn test_musig_three_signers() {
+        let (sk1, pk1) = SchnorrSig::generate_keypair();
+        let (sk2, pk2) = SchnorrSig::generate_keypair();
+        let (sk3, pk3) = SchnorrSig::generate_keypair();
+        let secret_keys = [sk1, sk2, sk3];
+
+        let aggregation = MuSigKeyAggregation::new(&[pk1, pk2, pk3]);
+
+        let nonces = [
+            MuSigNonce::generate(),
+            MuSigNonce::generate(),
+            MuSigNonce::generate(),
+        ];
+        let commitments: Vec<G1Affine> = nonces.iter().map(|n| n.commitment).collect();
+        let aggregate_nonce = MuSig::aggregate_nonce_commitments(&commitments);
+
+        let msg = b"Hello world!";
+
+        let partial_signatures: Vec<MuSigPartialSignature> = (0..3)
+            .map(|i| {
+                MuSig::partial_sign(
+                    &secret_keys[i],
+                    &nonces[i],
+                    aggregation.coefficient(i),
+                    &aggregation.aggregate_public_key,
+                    &aggregate_nonce,
+                    msg,
+                )
+            })
+            .collect();
+
+        let signature = MuSig::aggregate_signature(aggregate_nonce, &partial_signatures);
+
+        assert_eq!(
+            MuSig::verify(&aggregation.aggregate_public_key, msg, &signature),
+            true
+        );
+    }
+
+    #[test]
+    fn test_musig_rogue_key_attack_fails() {
+        // An honest participant's real key pair.
+        let (_, pk1) = SchnorrSig::generate_keypair();
+
+        // The attacker knows `sk_target` and tries to choose a second public key
+        // `rogue_pk2 = pk_target - pk1` so that naively summing `pk1 + rogue_pk2`
+        // collapses the aggregate key to `pk_target`, letting them sign alone for
+        // it without ever learning `sk1`.
+        let (_, pk_target) = SchnorrSig::generate_keypair();
+        let rogue_pk2 = PublicKey((G1Projective::from(pk_target.0) - G1Projective::from(pk1.0)).into_affine());
+
+        let aggregation = MuSigKeyAggregation::new(&[pk1, rogue_pk2]);
+
+        // Because each signer's contribution is scaled by a coefficient derived
+        // from `H(L || pk_i)`, not summed as bare points, the naive cancellation
+        // trick does not collapse the aggregate key to `pk_target`.
+        assert_ne!(aggregation.aggregate_public_key, pk_target);
+    }
+
+    fn threshold_sign_with(public_key: &PublicKey, shares: &[Share], msg: &[u8]) -> Signature {
+        let participant_indices: Vec<u64> = shares.iter().map(|s| s.index).collect();
+        let nonces: Vec<MuSigNonce> = shares.iter().map(|_| MuSigNonce::generate()).collect();
+        let commitments: Vec<(u64, G1Affine)> = shares
+            .iter()
+            .zip(&nonces)
+            .map(|(s, n)| (s.index, n.commitment))
+            .collect();
+        let combined_nonce = ThresholdSchnorr::combine_nonce_commitments(&commitments);
+
+        let partials: Vec<ThresholdPartialSignature> = shares
+            .iter()
+            .zip(&nonces)
+            .map(|(s, n)| {
+                ThresholdSchnorr::partial_sign(s, n, &participant_indices, public_key, &combined_nonce, msg)
+            })
+            .collect();
+
+        ThresholdSchnorr::combine(combined_nonce, &partials)
+    }
+
+    #[test]
+    fn test_threshold_verify_share() {
+        let (sk, _) = SchnorrSig::generate_keypair();
+        let key_shares = ThresholdSchnorr::split_key(&sk, 2, 3);
+
+        for share in &key_shares.shares {
+            assert_eq!(ThresholdSchnorr::verify_share(share, &key_shares.commitments), true);
+        }
+
+        let tampered_share = Share {
+            index: key_shares.shares[0].index,
+            scalar: key_shares.shares[0].scalar + ScalarField::from(1u64),
+        };
+        assert_eq!(
+            ThresholdSchnorr::verify_share(&tampered_share, &key_shares.commitments),
+            false
+        );
+    }
+
+    #[test]
+    fn test_threshold_signing_with_exact_threshold_verifies() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+        let key_shares = ThresholdSchnorr::split_key(&sk, 2, 3);
+        let msg = b"Hello world!";
+
+        let chosen = [key_shares.shares[0], key_shares.shares[2]];
+        let signature = threshold_sign_with(&pk, &chosen, msg);
+
+        assert_eq!(SchnorrSig::verify(&pk, msg, &signature), true);
+    }
+
+    #[test]
+    fn test_threshold_signing_below_threshold_fails() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+        let key_shares = ThresholdSchnorr::split_key(&sk, 2, 3);
+        let msg = b"Hello world!";
+
+        let chosen = [key_shares.shares[0]];
+        let signature = threshold_sign_with(&pk, &chosen, msg);
+
+        assert_eq!(SchnorrSig::verify(&pk, msg, &signature), false);
+    }
+
+    #[test]
+    fn test_vrf_prove_verify() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+        let input = b"block-height-42";
+
+        let (output, proof) = Vrf::prove(&sk, input);
+
+        assert_eq!(Vrf::verify(&pk, input, &output, &proof), true);
+    }
+
+    #[test]
+    fn test_vrf_output_is_deterministic() {
+        let (sk, _pk) = SchnorrSig::generate_keypair();
+        let input = b"block-height-42";
+
+        let (output_1, _proof_1) = Vrf::prove(&sk, input);
+        let (output_2, _proof_2) = Vrf::prove(&sk, input);
+
+        assert_eq!(output_1, output_2);
+    }
+
+    #[test]
+    fn test_vrf_verify_rejects_wrong_input() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+
+        let (output, proof) = Vrf::prove(&sk, b"block-height-42");
+
+        assert_eq!(Vrf::verify(&pk, b"block-height-43", &output, &proof), false);
+    }
+
+    #[test]
+    fn test_vrf_verify_rejects_wrong_public_key() {
+        let (sk, _pk) = SchnorrSig::generate_keypair();
+        let (_other_sk, other_pk) = SchnorrSig::generate_keypair();
+        let input = b"block-height-42";
+
+        let (output, proof) = Vrf::prove(&sk, input);
+
+        assert_eq!(Vrf::verify(&other_pk, input, &output, &proof), false);
+    }
+
+    #[test]
+    fn test_vrf_verify_rejects_tampered_output() {
+        let (sk, pk) = SchnorrSig::generate_keypair();
+        let input = b"block-height-42";
+
+        let (mut output, proof) = Vrf::prove(&sk, input);
+        output.0[0] ^= 0xff;
+
+        assert_eq!(Vrf::verify(&pk, input, &output, &proof), false);
+    }
 }